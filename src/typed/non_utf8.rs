@@ -1,6 +1,28 @@
+use std::borrow::Cow;
 use std::convert::TryFrom;
+use std::ffi::{OsStr, OsString};
 use std::path::{Path, PathBuf};
 
+mod components;
+pub use components::{TypedComponent, TypedComponents};
+
+mod encoding;
+pub use encoding::{UnixToWindowsEncodingError, WindowsToUnixEncodingOptions};
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+#[cfg(feature = "proptest")]
+mod proptest_impls;
+#[cfg(feature = "proptest")]
+pub use proptest_impls::{
+    typed_path_buf_strategy, unix_path_buf_strategy, utf8_unix_path_buf_strategy,
+    utf8_windows_path_buf_strategy, windows_path_buf_strategy,
+};
+
+mod wtf8;
+pub use wtf8::Wtf8Error;
+
 use crate::convert::TryAsRef;
 use crate::unix::{UnixPath, UnixPathBuf};
 use crate::windows::{WindowsPath, WindowsPathBuf};
@@ -9,6 +31,7 @@ use crate::windows::{WindowsPath, WindowsPathBuf};
 ///
 /// * [`UnixPath`]
 /// * [`WindowsPath`]
+#[derive(Copy, Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum TypedPath<'a> {
     Unix(&'a UnixPath),
     Windows(&'a WindowsPath),
@@ -56,6 +79,14 @@ impl<'a> TypedPath<'a> {
         matches!(self, Self::Windows(_))
     }
 
+    /// Returns the underlying bytes backing this path, regardless of variant.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        match self {
+            Self::Unix(path) => path.as_bytes(),
+            Self::Windows(path) => path.as_bytes(),
+        }
+    }
+
     /// Converts into a [`TypedPathBuf`].
     pub fn to_path_buf(&self) -> TypedPathBuf {
         match self {
@@ -63,6 +94,427 @@ impl<'a> TypedPath<'a> {
             Self::Windows(path) => TypedPathBuf::Windows(path.to_path_buf()),
         }
     }
+
+    /// Produces an iterator over the [`TypedComponent`]s of the path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::{TypedComponent, TypedPath, UnixComponent};
+    ///
+    /// let path = TypedPath::new(b"/tmp/foo.txt");
+    /// let components: Vec<_> = path.components().collect();
+    /// assert_eq!(
+    ///     components,
+    ///     vec![
+    ///         TypedComponent::Unix(UnixComponent::RootDir),
+    ///         TypedComponent::Unix(UnixComponent::Normal(b"tmp")),
+    ///         TypedComponent::Unix(UnixComponent::Normal(b"foo.txt")),
+    ///     ],
+    /// );
+    /// ```
+    pub fn components(&self) -> TypedComponents<'a> {
+        match self {
+            Self::Unix(path) => TypedComponents::Unix(path.components()),
+            Self::Windows(path) => TypedComponents::Windows(path.components()),
+        }
+    }
+
+    /// Returns the `TypedPath` without its final component, if there is one.
+    ///
+    /// Returns [`None`] if the path terminates in a root or prefix.
+    pub fn parent(&self) -> Option<TypedPath<'a>> {
+        match self {
+            Self::Unix(path) => path.parent().map(TypedPath::Unix),
+            Self::Windows(path) => path.parent().map(TypedPath::Windows),
+        }
+    }
+
+    /// Returns the final component of the path, if there is one.
+    ///
+    /// If the path is a normal file, this is the file name. If it's the path of a directory,
+    /// this is the directory name.
+    ///
+    /// Returns [`None`] if the path terminates in `..`.
+    pub fn file_name(&self) -> Option<&'a [u8]> {
+        match self {
+            Self::Unix(path) => path.file_name(),
+            Self::Windows(path) => path.file_name(),
+        }
+    }
+
+    /// Extracts the stem (non-extension) portion of [`TypedPath::file_name`].
+    ///
+    /// The stem is:
+    ///
+    /// * [`None`], if there is no file name;
+    /// * The entire file name if there is no embedded `.`;
+    /// * The entire file name if the file name begins with `.` and has no other `.`s within;
+    /// * Otherwise, the portion of the file name before the final `.`
+    pub fn file_stem(&self) -> Option<&'a [u8]> {
+        match self {
+            Self::Unix(path) => path.file_stem(),
+            Self::Windows(path) => path.file_stem(),
+        }
+    }
+
+    /// Extracts the extension of [`TypedPath::file_name`], if possible.
+    pub fn extension(&self) -> Option<&'a [u8]> {
+        match self {
+            Self::Unix(path) => path.extension(),
+            Self::Windows(path) => path.extension(),
+        }
+    }
+
+    /// Creates an owned [`TypedPathBuf`] with `path` adjoined to `self`, preserving the
+    /// variant (Unix or Windows) of `self`.
+    pub fn join(&self, path: impl AsRef<[u8]>) -> TypedPathBuf {
+        match self {
+            Self::Unix(p) => TypedPathBuf::Unix(p.join(UnixPath::new(path.as_ref()))),
+            Self::Windows(p) => TypedPathBuf::Windows(p.join(WindowsPath::new(path.as_ref()))),
+        }
+    }
+
+    /// Creates an owned [`TypedPathBuf`] like `self` but with the given file name.
+    pub fn with_file_name(&self, file_name: impl AsRef<[u8]>) -> TypedPathBuf {
+        match self {
+            Self::Unix(path) => TypedPathBuf::Unix(path.with_file_name(file_name.as_ref())),
+            Self::Windows(path) => {
+                TypedPathBuf::Windows(path.with_file_name(file_name.as_ref()))
+            }
+        }
+    }
+
+    /// Creates an owned [`TypedPathBuf`] like `self` but with the given extension.
+    pub fn with_extension(&self, extension: impl AsRef<[u8]>) -> TypedPathBuf {
+        match self {
+            Self::Unix(path) => TypedPathBuf::Unix(path.with_extension(extension.as_ref())),
+            Self::Windows(path) => {
+                TypedPathBuf::Windows(path.with_extension(extension.as_ref()))
+            }
+        }
+    }
+
+    /// Determines whether `base` is a prefix of `self`.
+    ///
+    /// Only considers whole path components to match.
+    pub fn starts_with(&self, base: impl AsRef<[u8]>) -> bool {
+        match self {
+            Self::Unix(path) => path.starts_with(UnixPath::new(base.as_ref())),
+            Self::Windows(path) => path.starts_with(WindowsPath::new(base.as_ref())),
+        }
+    }
+
+    /// Determines whether `child` is a suffix of `self`.
+    ///
+    /// Only considers whole path components to match.
+    pub fn ends_with(&self, child: impl AsRef<[u8]>) -> bool {
+        match self {
+            Self::Unix(path) => path.ends_with(UnixPath::new(child.as_ref())),
+            Self::Windows(path) => path.ends_with(WindowsPath::new(child.as_ref())),
+        }
+    }
+
+    /// Lexically normalizes the path, purely syntactically and without touching the
+    /// filesystem.
+    ///
+    /// A `.` component is dropped. A `..` component pops the preceding `Normal` component off the
+    /// path; if there is no preceding `Normal` component (the stack is empty, the top is a root,
+    /// or the top is another `..`), the `..` is either discarded (when it would walk above a
+    /// root) or retained literally (when the path is relative and could still be joined onto a
+    /// base that supplies more components). The root/prefix of the path, if any, is always kept
+    /// at the front and is never popped.
+    ///
+    /// The result preserves the variant (Unix or Windows) of `self` and re-serializes using that
+    /// variant's native separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert_eq!(
+    ///     TypedPath::new(b"/a/b/../c/./d").normalize(),
+    ///     TypedPath::new(b"/a/c/d").to_path_buf(),
+    /// );
+    /// assert_eq!(
+    ///     TypedPath::new(b"a/../../b").normalize(),
+    ///     TypedPath::new(b"../b").to_path_buf(),
+    /// );
+    /// assert_eq!(
+    ///     TypedPath::new(b"/../a").normalize(),
+    ///     TypedPath::new(b"/a").to_path_buf(),
+    /// );
+    /// ```
+    pub fn normalize(&self) -> TypedPathBuf {
+        let mut stack: Vec<TypedComponent<'a>> = Vec::new();
+
+        for component in self.components() {
+            if component.is_current() {
+                continue;
+            }
+
+            if component.is_parent() {
+                match stack.last() {
+                    // `..` pops a preceding normal component, e.g. `a/..` -> ``
+                    Some(top) if top.is_normal() => {
+                        stack.pop();
+                    }
+                    // `..` walking above a root is simply discarded, e.g. `/..` -> `/`
+                    Some(top) if top.is_root() => {}
+                    // Otherwise retain the `..` literally: either the stack is empty (relative
+                    // path with a leading `..`) or the top is itself a `..`
+                    _ => stack.push(component),
+                }
+            } else {
+                stack.push(component);
+            }
+        }
+
+        let mut path = match self {
+            Self::Unix(_) => TypedPathBuf::Unix(UnixPathBuf::new()),
+            Self::Windows(_) => TypedPathBuf::Windows(WindowsPathBuf::new()),
+        };
+
+        for component in stack {
+            path.push(component.as_bytes());
+        }
+
+        path
+    }
+
+    /// Joins `self` onto `base` if `self` is relative, and then [`TypedPath::normalize`]s the
+    /// result.
+    ///
+    /// Since a [`TypedPath`] may not correspond to the current platform, there is no notion of a
+    /// "current working directory" to fall back on, so the base must be supplied by the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert_eq!(
+    ///     TypedPath::new(b"c/d").absolutize(b"/a/b"),
+    ///     TypedPath::new(b"/a/b/c/d").to_path_buf(),
+    /// );
+    /// assert_eq!(
+    ///     TypedPath::new(b"/c/d").absolutize(b"/a/b"),
+    ///     TypedPath::new(b"/c/d").to_path_buf(),
+    /// );
+    /// ```
+    pub fn absolutize(&self, base: impl AsRef<[u8]>) -> TypedPathBuf {
+        let is_absolute = self
+            .components()
+            .next()
+            .map(|component| component.is_root())
+            .unwrap_or(false);
+
+        let joined = if is_absolute {
+            self.to_path_buf()
+        } else {
+            match self {
+                Self::Unix(path) => TypedPathBuf::Unix(UnixPath::new(base.as_ref()).join(path)),
+                Self::Windows(path) => {
+                    TypedPathBuf::Windows(WindowsPath::new(base.as_ref()).join(path))
+                }
+            }
+        };
+
+        joined.as_path().normalize()
+    }
+
+    /// Re-encodes the path as its Unix spelling using the default
+    /// [`WindowsToUnixEncodingOptions`].
+    ///
+    /// If `self` is already a [`TypedPath::Unix`], this is equivalent to
+    /// [`TypedPath::to_path_buf`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert_eq!(
+    ///     TypedPath::new(br"C:\Users\a\b.txt").with_encoding_unix(),
+    ///     typed_path::UnixPath::new(b"/c/Users/a/b.txt"),
+    /// );
+    /// ```
+    pub fn with_encoding_unix(&self) -> UnixPathBuf {
+        self.with_encoding_unix_opts(&WindowsToUnixEncodingOptions::default())
+    }
+
+    /// Re-encodes the path as its Unix spelling, customizing drive-letter handling via
+    /// `options`.
+    ///
+    /// Verbatim `\\?\` prefixes are dropped, `\` separators become `/`, and a drive letter
+    /// prefix such as `C:` is rewritten using `options.drive_mapping`. Any other prefix (e.g. a
+    /// UNC share) is carried over with its separators flipped rather than silently dropped.
+    pub fn with_encoding_unix_opts(&self, options: &WindowsToUnixEncodingOptions) -> UnixPathBuf {
+        if let Self::Unix(path) = self {
+            return path.to_path_buf();
+        }
+
+        let mut bytes: Vec<u8> = Vec::new();
+
+        for component in self.components() {
+            let raw = component.as_bytes();
+            let is_prefix = !component.is_root()
+                && !component.is_normal()
+                && !component.is_parent()
+                && !component.is_current();
+
+            if is_prefix {
+                // Strip the verbatim marker but keep looking at what follows it (e.g. the
+                // drive letter in `\\?\C:`) instead of discarding the whole prefix.
+                let raw = raw.strip_prefix(br"\\?\").unwrap_or(raw);
+
+                if raw.len() == 2 && raw[1] == b':' && raw[0].is_ascii_alphabetic() {
+                    bytes.extend((options.drive_mapping)(raw[0]));
+                } else {
+                    bytes.extend(raw.iter().map(|&b| if b == b'\\' { b'/' } else { b }));
+                }
+
+                continue;
+            }
+
+            if component.is_root() {
+                if !bytes.ends_with(b"/") {
+                    bytes.push(b'/');
+                }
+                continue;
+            }
+
+            if !bytes.is_empty() && !bytes.ends_with(b"/") {
+                bytes.push(b'/');
+            }
+
+            bytes.extend_from_slice(raw);
+        }
+
+        if bytes.is_empty() {
+            bytes.push(b'/');
+        }
+
+        UnixPathBuf::from(bytes)
+    }
+
+    /// Re-encodes the path as its Windows spelling.
+    ///
+    /// If `self` is already a [`TypedPath::Windows`], this is equivalent to
+    /// [`TypedPath::to_path_buf`]. Otherwise, `/` separators become `\` and a leading `/`
+    /// becomes a rooted-but-prefixless Windows path (e.g. `\foo\bar`).
+    ///
+    /// Unlike [`TypedPath::with_encoding_unix`], this can fail: Unix normal components may
+    /// legally contain a literal `\` byte (only `/` and NUL are reserved on Unix), and carrying
+    /// one over unescaped would fracture it into extra Windows components instead of producing
+    /// the same logical path. Returns [`UnixToWindowsEncodingError`] if that happens.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use typed_path::TypedPath;
+    ///
+    /// assert_eq!(
+    ///     TypedPath::new(b"/a/b.txt").with_encoding_windows().unwrap(),
+    ///     typed_path::WindowsPath::new(br"\a\b.txt"),
+    /// );
+    /// ```
+    pub fn with_encoding_windows(&self) -> Result<WindowsPathBuf, UnixToWindowsEncodingError> {
+        if let Self::Windows(path) = self {
+            return Ok(path.to_path_buf());
+        }
+
+        let mut bytes: Vec<u8> = Vec::new();
+
+        for component in self.components() {
+            let raw = component.as_bytes();
+
+            if component.is_root() {
+                bytes.push(b'\\');
+                continue;
+            }
+
+            if raw.contains(&b'\\') {
+                return Err(UnixToWindowsEncodingError);
+            }
+
+            if !bytes.is_empty() && !bytes.ends_with(b"\\") {
+                bytes.push(b'\\');
+            }
+
+            bytes.extend_from_slice(raw);
+        }
+
+        Ok(WindowsPathBuf::from(bytes))
+    }
+
+    /// Attempts a lossless conversion to an [`OsString`], regardless of the host platform.
+    ///
+    /// On Unix, the bytes map directly to an `OsStr`. On Windows, the bytes are decoded as
+    /// WTF-8 (UTF-8 that additionally permits lone surrogates) into their 16-bit
+    /// representation, so byte sequences that did not originate from valid UTF-8 still survive
+    /// the round trip. On Unix and Windows, this only fails when the bytes are not well-formed
+    /// WTF-8; on any other platform, it always fails with [`Wtf8Error`].
+    pub fn try_to_os_string(&self) -> Result<OsString, Wtf8Error> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            Ok(OsStr::from_bytes(self.as_bytes()).to_os_string())
+        }
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStringExt;
+            Ok(OsString::from_wide(&wtf8::decode(self.as_bytes())?))
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            Err(Wtf8Error)
+        }
+    }
+
+    /// Like [`TypedPath::try_to_os_string`], but substitutes U+FFFD REPLACEMENT CHARACTER for
+    /// any byte sequence that cannot be represented, rather than failing.
+    pub fn to_os_str_lossy(&self) -> Cow<'_, OsStr> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+            Cow::Borrowed(OsStr::from_bytes(self.as_bytes()))
+        }
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStringExt;
+            let units = wtf8::decode(self.as_bytes()).unwrap_or_else(|_| {
+                String::from_utf8_lossy(self.as_bytes())
+                    .encode_utf16()
+                    .collect()
+            });
+            Cow::Owned(OsString::from_wide(&units))
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            Cow::Owned(OsString::from(
+                String::from_utf8_lossy(self.as_bytes()).into_owned(),
+            ))
+        }
+    }
+
+    /// Attempts a lossless conversion to a [`PathBuf`], regardless of the host platform. See
+    /// [`TypedPath::try_to_os_string`].
+    pub fn try_to_path(&self) -> Result<PathBuf, Wtf8Error> {
+        self.try_to_os_string().map(PathBuf::from)
+    }
+
+    /// Like [`TypedPath::try_to_path`], but substitutes U+FFFD REPLACEMENT CHARACTER for any
+    /// byte sequence that cannot be represented, rather than failing. See
+    /// [`TypedPath::to_os_str_lossy`].
+    pub fn to_path_lossy(&self) -> PathBuf {
+        PathBuf::from(self.to_os_str_lossy().into_owned())
+    }
 }
 
 impl<'a> From<&'a [u8]> for TypedPath<'a> {
@@ -101,6 +553,7 @@ impl TryAsRef<WindowsPath> for TypedPath<'_> {
 ///
 /// * [`UnixPathBuf`]
 /// * [`WindowsPathBuf`]
+#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum TypedPathBuf {
     Unix(UnixPathBuf),
     Windows(WindowsPathBuf),
@@ -126,6 +579,148 @@ impl TypedPathBuf {
             Self::Windows(path) => TypedPath::Windows(path.as_path()),
         }
     }
+
+    /// Returns the underlying bytes backing this path, regardless of variant.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Unix(path) => path.as_bytes(),
+            Self::Windows(path) => path.as_bytes(),
+        }
+    }
+
+    /// See [`TypedPath::components`].
+    pub fn components(&self) -> TypedComponents<'_> {
+        self.as_path().components()
+    }
+
+    /// See [`TypedPath::parent`].
+    pub fn parent(&self) -> Option<TypedPath<'_>> {
+        self.as_path().parent()
+    }
+
+    /// See [`TypedPath::file_name`].
+    pub fn file_name(&self) -> Option<&[u8]> {
+        self.as_path().file_name()
+    }
+
+    /// See [`TypedPath::file_stem`].
+    pub fn file_stem(&self) -> Option<&[u8]> {
+        self.as_path().file_stem()
+    }
+
+    /// See [`TypedPath::extension`].
+    pub fn extension(&self) -> Option<&[u8]> {
+        self.as_path().extension()
+    }
+
+    /// See [`TypedPath::join`].
+    pub fn join(&self, path: impl AsRef<[u8]>) -> TypedPathBuf {
+        self.as_path().join(path)
+    }
+
+    /// See [`TypedPath::with_file_name`].
+    pub fn with_file_name(&self, file_name: impl AsRef<[u8]>) -> TypedPathBuf {
+        self.as_path().with_file_name(file_name)
+    }
+
+    /// See [`TypedPath::with_extension`].
+    pub fn with_extension(&self, extension: impl AsRef<[u8]>) -> TypedPathBuf {
+        self.as_path().with_extension(extension)
+    }
+
+    /// See [`TypedPath::starts_with`].
+    pub fn starts_with(&self, base: impl AsRef<[u8]>) -> bool {
+        self.as_path().starts_with(base)
+    }
+
+    /// See [`TypedPath::ends_with`].
+    pub fn ends_with(&self, child: impl AsRef<[u8]>) -> bool {
+        self.as_path().ends_with(child)
+    }
+
+    /// See [`TypedPath::normalize`].
+    pub fn normalize(&self) -> TypedPathBuf {
+        self.as_path().normalize()
+    }
+
+    /// See [`TypedPath::absolutize`].
+    pub fn absolutize(&self, base: impl AsRef<[u8]>) -> TypedPathBuf {
+        self.as_path().absolutize(base)
+    }
+
+    /// Extends `self` with `path`, following the same rules as
+    /// [`TypedPath::join`] for the variant (Unix or Windows) of `self`.
+    pub fn push(&mut self, path: impl AsRef<[u8]>) {
+        match self {
+            Self::Unix(p) => p.push(UnixPath::new(path.as_ref())),
+            Self::Windows(p) => p.push(WindowsPath::new(path.as_ref())),
+        }
+    }
+
+    /// Truncates `self` to [`TypedPath::parent`].
+    ///
+    /// Returns `false` and does nothing if [`TypedPath::parent`] is [`None`].
+    /// Otherwise, returns `true`.
+    pub fn pop(&mut self) -> bool {
+        match self {
+            Self::Unix(path) => path.pop(),
+            Self::Windows(path) => path.pop(),
+        }
+    }
+
+    /// Updates [`TypedPath::file_name`] to `file_name`.
+    pub fn set_file_name(&mut self, file_name: impl AsRef<[u8]>) {
+        match self {
+            Self::Unix(path) => path.set_file_name(file_name.as_ref()),
+            Self::Windows(path) => path.set_file_name(file_name.as_ref()),
+        }
+    }
+
+    /// Updates [`TypedPath::extension`] to `extension`.
+    ///
+    /// Returns `false` and does nothing if [`TypedPath::file_name`] is [`None`].
+    /// Otherwise, returns `true`.
+    pub fn set_extension(&mut self, extension: impl AsRef<[u8]>) -> bool {
+        match self {
+            Self::Unix(path) => path.set_extension(extension.as_ref()),
+            Self::Windows(path) => path.set_extension(extension.as_ref()),
+        }
+    }
+
+    /// See [`TypedPath::with_encoding_unix`].
+    pub fn with_encoding_unix(&self) -> UnixPathBuf {
+        self.as_path().with_encoding_unix()
+    }
+
+    /// See [`TypedPath::with_encoding_unix_opts`].
+    pub fn with_encoding_unix_opts(&self, options: &WindowsToUnixEncodingOptions) -> UnixPathBuf {
+        self.as_path().with_encoding_unix_opts(options)
+    }
+
+    /// See [`TypedPath::with_encoding_windows`].
+    pub fn with_encoding_windows(&self) -> Result<WindowsPathBuf, UnixToWindowsEncodingError> {
+        self.as_path().with_encoding_windows()
+    }
+
+    /// See [`TypedPath::try_to_os_string`].
+    pub fn try_to_os_string(&self) -> Result<OsString, Wtf8Error> {
+        self.as_path().try_to_os_string()
+    }
+
+    /// See [`TypedPath::to_os_str_lossy`].
+    pub fn to_os_string_lossy(&self) -> OsString {
+        self.as_path().to_os_str_lossy().into_owned()
+    }
+
+    /// See [`TypedPath::try_to_path`].
+    pub fn try_to_path_buf(&self) -> Result<PathBuf, Wtf8Error> {
+        self.as_path().try_to_path()
+    }
+
+    /// See [`TypedPath::to_path_lossy`].
+    pub fn to_path_buf_lossy(&self) -> PathBuf {
+        self.as_path().to_path_lossy()
+    }
 }
 
 impl<'a, const N: usize> From<&'a [u8; N]> for TypedPathBuf {
@@ -237,3 +832,228 @@ impl TryFrom<TypedPathBuf> for PathBuf {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_encoding_unix_should_extract_drive_letter_from_verbatim_prefix() {
+        let path = TypedPath::new(br"\\?\C:\Users\a.txt");
+        assert_eq!(path.with_encoding_unix(), UnixPath::new(b"/c/Users/a.txt"));
+    }
+
+    #[test]
+    fn with_encoding_unix_should_map_a_plain_drive_prefix() {
+        let path = TypedPath::new(br"C:\Users\a.txt");
+        assert_eq!(path.with_encoding_unix(), UnixPath::new(b"/c/Users/a.txt"));
+    }
+
+    #[test]
+    fn with_encoding_unix_should_flip_separators_in_a_unc_prefix_rather_than_drop_it() {
+        let path = TypedPath::new(br"\\server\share\a.txt");
+        assert_eq!(
+            path.with_encoding_unix(),
+            UnixPath::new(b"/server/share/a.txt"),
+        );
+    }
+
+    #[test]
+    fn with_encoding_unix_should_honor_a_custom_drive_mapping() {
+        let options = WindowsToUnixEncodingOptions {
+            drive_mapping: |drive| {
+                let mut mapped = b"/mnt/".to_vec();
+                mapped.push(drive.to_ascii_lowercase());
+                mapped
+            },
+        };
+        let path = TypedPath::new(br"C:\Users\a.txt");
+        assert_eq!(
+            path.with_encoding_unix_opts(&options),
+            UnixPath::new(b"/mnt/c/Users/a.txt"),
+        );
+    }
+
+    #[test]
+    fn with_encoding_unix_should_be_a_no_op_for_a_unix_path() {
+        let path = TypedPath::new(b"/tmp/foo.txt");
+        assert_eq!(path.with_encoding_unix(), UnixPath::new(b"/tmp/foo.txt"));
+    }
+
+    #[test]
+    fn with_encoding_windows_should_root_a_unix_path_without_a_prefix() {
+        let path = TypedPath::new(b"/a/b.txt");
+        assert_eq!(
+            path.with_encoding_windows().unwrap(),
+            WindowsPath::new(br"\a\b.txt"),
+        );
+    }
+
+    #[test]
+    fn with_encoding_windows_should_reject_a_component_containing_a_literal_backslash() {
+        let path = TypedPath::new(b"/a/b\\c.txt");
+        assert_eq!(
+            path.with_encoding_windows(),
+            Err(UnixToWindowsEncodingError),
+        );
+    }
+
+    #[test]
+    fn typed_path_buf_should_delegate_join_to_typed_path() {
+        let path = TypedPathBuf::from(b"/tmp".as_slice());
+        assert_eq!(path.join(b"foo.txt"), TypedPathBuf::from(b"/tmp/foo.txt".as_slice()));
+    }
+
+    #[test]
+    fn typed_path_buf_should_delegate_with_file_name_and_with_extension() {
+        let path = TypedPathBuf::from(b"/tmp/foo.txt".as_slice());
+        assert_eq!(
+            path.with_file_name(b"bar.md"),
+            TypedPathBuf::from(b"/tmp/bar.md".as_slice()),
+        );
+        assert_eq!(
+            path.with_extension(b"md"),
+            TypedPathBuf::from(b"/tmp/foo.md".as_slice()),
+        );
+    }
+
+    #[test]
+    fn typed_path_buf_should_delegate_starts_with_and_ends_with() {
+        let path = TypedPathBuf::from(b"/tmp/foo.txt".as_slice());
+        assert!(path.starts_with(b"/tmp"));
+        assert!(path.ends_with(b"foo.txt"));
+        assert!(!path.starts_with(b"/usr"));
+    }
+
+    #[test]
+    fn push_should_append_a_relative_component() {
+        let mut path = TypedPathBuf::from(b"/tmp".as_slice());
+        path.push(b"foo.txt");
+        assert_eq!(path, TypedPathBuf::from(b"/tmp/foo.txt".as_slice()));
+    }
+
+    #[test]
+    fn push_should_replace_with_an_absolute_component() {
+        let mut path = TypedPathBuf::from(b"/tmp".as_slice());
+        path.push(b"/etc/foo.txt");
+        assert_eq!(path, TypedPathBuf::from(b"/etc/foo.txt".as_slice()));
+    }
+
+    #[test]
+    fn pop_should_remove_the_final_component_and_report_whether_it_did() {
+        let mut path = TypedPathBuf::from(b"/tmp/foo.txt".as_slice());
+        assert!(path.pop());
+        assert_eq!(path, TypedPathBuf::from(b"/tmp".as_slice()));
+
+        let mut root = TypedPathBuf::from(b"/".as_slice());
+        assert!(!root.pop());
+        assert_eq!(root, TypedPathBuf::from(b"/".as_slice()));
+    }
+
+    #[test]
+    fn set_file_name_should_replace_the_final_component() {
+        let mut path = TypedPathBuf::from(b"/tmp/foo.txt".as_slice());
+        path.set_file_name(b"bar.md");
+        assert_eq!(path, TypedPathBuf::from(b"/tmp/bar.md".as_slice()));
+    }
+
+    #[test]
+    fn set_extension_should_replace_the_extension_and_report_whether_it_did() {
+        let mut path = TypedPathBuf::from(b"/tmp/foo.txt".as_slice());
+        assert!(path.set_extension(b"md"));
+        assert_eq!(path, TypedPathBuf::from(b"/tmp/foo.md".as_slice()));
+
+        let mut no_file_name = TypedPathBuf::from(b"/".as_slice());
+        assert!(!no_file_name.set_extension(b"md"));
+        assert_eq!(no_file_name, TypedPathBuf::from(b"/".as_slice()));
+    }
+
+    #[test]
+    fn normalize_should_discard_a_parent_dir_that_would_walk_above_an_absolute_root() {
+        let path = TypedPath::new(b"/../a");
+        assert_eq!(path.normalize(), TypedPathBuf::from(b"/a".as_slice()));
+    }
+
+    #[test]
+    fn normalize_should_discard_repeated_leading_parent_dirs_on_an_absolute_path() {
+        let path = TypedPath::new(b"/../../a/..");
+        assert_eq!(path.normalize(), TypedPathBuf::from(b"/".as_slice()));
+    }
+
+    #[test]
+    fn normalize_should_retain_a_leading_parent_dir_on_a_relative_path() {
+        let path = TypedPath::new(b"../a");
+        assert_eq!(path.normalize(), TypedPathBuf::from(b"../a".as_slice()));
+    }
+
+    #[test]
+    fn normalize_should_retain_repeated_leading_parent_dirs_on_a_relative_path() {
+        let path = TypedPath::new(b"../../a/../../b");
+        assert_eq!(path.normalize(), TypedPathBuf::from(b"../../../b".as_slice()));
+    }
+
+    #[test]
+    fn normalize_should_discard_a_parent_dir_that_would_walk_above_a_windows_prefix_root() {
+        let path = TypedPath::new(br"C:\..\a");
+        assert_eq!(path.normalize(), TypedPathBuf::from(br"C:\a".as_slice()));
+    }
+
+    #[test]
+    fn normalize_should_drop_current_dir_components() {
+        let path = TypedPath::new(b"/a/./b/.");
+        assert_eq!(path.normalize(), TypedPathBuf::from(b"/a/b".as_slice()));
+    }
+
+    #[test]
+    fn absolutize_should_leave_an_already_absolute_path_untouched_other_than_normalizing() {
+        let path = TypedPath::new(b"/a/../b");
+        assert_eq!(
+            path.absolutize(b"/base"),
+            TypedPathBuf::from(b"/b".as_slice()),
+        );
+    }
+
+    #[test]
+    fn absolutize_should_join_a_relative_path_onto_the_supplied_base_and_normalize() {
+        let path = TypedPath::new(b"../a");
+        assert_eq!(
+            path.absolutize(b"/base/dir"),
+            TypedPathBuf::from(b"/base/a".as_slice()),
+        );
+    }
+
+    #[test]
+    fn typed_path_buf_normalize_and_absolutize_should_delegate_to_typed_path() {
+        let path = TypedPathBuf::from(b"/a/../b".as_slice());
+        assert_eq!(path.normalize(), TypedPathBuf::from(b"/b".as_slice()));
+        assert_eq!(
+            TypedPathBuf::from(b"c".as_slice()).absolutize(b"/a/b"),
+            TypedPathBuf::from(b"/a/b/c".as_slice()),
+        );
+    }
+
+    #[test]
+    fn try_to_os_string_should_losslessly_round_trip_valid_utf8() {
+        let path = TypedPath::new("résumé.txt".as_bytes());
+        assert_eq!(
+            path.try_to_os_string().unwrap(),
+            std::ffi::OsString::from("résumé.txt"),
+        );
+    }
+
+    #[test]
+    fn to_os_str_lossy_should_substitute_invalid_sequences() {
+        // On Unix, `to_os_str_lossy` keeps the `\xff` byte as is (every byte sequence is a valid
+        // `OsStr` there) and the U+FFFD checked below comes from `to_string_lossy`; on Windows,
+        // `to_os_str_lossy` itself substitutes it.
+        let path = TypedPath::new(b"\xffbad.txt");
+        let os_string = path.to_os_str_lossy().into_owned();
+        assert_eq!(os_string.to_string_lossy(), "\u{FFFD}bad.txt");
+    }
+
+    #[test]
+    fn typed_path_buf_try_to_path_buf_should_delegate_to_typed_path() {
+        let path = TypedPathBuf::from(b"/tmp/foo.txt".as_slice());
+        assert_eq!(path.try_to_path_buf().unwrap(), PathBuf::from("/tmp/foo.txt"));
+    }
+}
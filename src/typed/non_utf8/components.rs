@@ -0,0 +1,35 @@
+mod component;
+pub use component::TypedComponent;
+
+use crate::unix::UnixComponents;
+use crate::windows::WindowsComponents;
+
+/// Iterator over the [`TypedComponent`]s of a [`TypedPath`](crate::TypedPath).
+///
+/// Mirrors the variant of path it was created from, yielding [`TypedComponent::Unix`] or
+/// [`TypedComponent::Windows`] items for the lifetime of the iterator.
+#[derive(Clone, Debug)]
+pub enum TypedComponents<'a> {
+    Unix(UnixComponents<'a>),
+    Windows(WindowsComponents<'a>),
+}
+
+impl<'a> Iterator for TypedComponents<'a> {
+    type Item = TypedComponent<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Unix(components) => components.next().map(TypedComponent::Unix),
+            Self::Windows(components) => components.next().map(TypedComponent::Windows),
+        }
+    }
+}
+
+impl<'a> DoubleEndedIterator for TypedComponents<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Unix(components) => components.next_back().map(TypedComponent::Unix),
+            Self::Windows(components) => components.next_back().map(TypedComponent::Windows),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::fmt;
+
+/// Options controlling how a Windows path is re-encoded into its Unix spelling via
+/// [`TypedPath::with_encoding_unix_opts`](crate::TypedPath::with_encoding_unix_opts).
+#[derive(Clone)]
+pub struct WindowsToUnixEncodingOptions {
+    /// Maps a drive letter (e.g. `b'C'`) from a Windows prefix such as `C:` into the bytes used
+    /// to represent it as a Unix path segment.
+    ///
+    /// The default lower-cases the letter and wraps it in `/`, e.g. `C:` becomes `/c/`.
+    pub drive_mapping: fn(u8) -> Vec<u8>,
+}
+
+impl Default for WindowsToUnixEncodingOptions {
+    /// Maps a drive letter such as `C:` to `/c/`.
+    fn default() -> Self {
+        Self {
+            drive_mapping: |drive| vec![b'/', drive.to_ascii_lowercase(), b'/'],
+        }
+    }
+}
+
+impl std::fmt::Debug for WindowsToUnixEncodingOptions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WindowsToUnixEncodingOptions").finish()
+    }
+}
+
+/// Indicates that a Unix path component contained a literal `\`, which Windows reserves as a
+/// separator, so re-encoding it as a Windows path via
+/// [`TypedPath::with_encoding_windows`](crate::TypedPath::with_encoding_windows) would change
+/// the number of components rather than just their spelling.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct UnixToWindowsEncodingError;
+
+impl fmt::Display for UnixToWindowsEncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "path component contains a literal '\\', which is reserved as a separator on Windows"
+        )
+    }
+}
+
+impl std::error::Error for UnixToWindowsEncodingError {}
@@ -0,0 +1,274 @@
+use proptest::prelude::*;
+
+use crate::unix::{UnixPathBuf, Utf8UnixPathBuf};
+use crate::windows::{Utf8WindowsPathBuf, WindowsPathBuf};
+
+use super::TypedPathBuf;
+
+/// Generates a single path component: mostly ordinary names, occasionally `.`/`..`, and
+/// occasionally a byte sequence that is not valid UTF-8 so non-UTF-8 round-trip invariants get
+/// exercised too.
+fn component_bytes_strategy() -> impl Strategy<Value = Vec<u8>> {
+    prop_oneof![
+        6 => "[a-zA-Z0-9_-]{1,8}".prop_map(|s| s.into_bytes()),
+        1 => Just(b".".to_vec()),
+        1 => Just(b"..".to_vec()),
+        // Excludes `/` and `\` -- both Unix and Windows treat one of these as *the* separator,
+        // and including either here would let a "single component" silently fracture into
+        // multiple components once the generated bytes are parsed back into a path.
+        1 => prop::collection::vec(non_separator_byte_strategy(), 1..4).prop_map(|mut bytes| {
+            // Force at least one byte that can never appear in valid UTF-8.
+            bytes.push(0xfe);
+            bytes
+        }),
+    ]
+}
+
+/// Generates a single byte, excluding `/` (0x2f) and `\` (0x5c) so callers can build "single
+/// component" byte strategies that won't accidentally fracture into multiple components once
+/// parsed back by [`UnixPath`](crate::UnixPath)/[`WindowsPath`](crate::WindowsPath).
+fn non_separator_byte_strategy() -> impl Strategy<Value = u8> {
+    (1u8..=0xff).prop_filter("must not be a path separator", |b| *b != b'/' && *b != b'\\')
+}
+
+/// Generates a single path component as a valid UTF-8 [`String`], mostly ordinary names,
+/// occasionally `.`/`..`, and occasionally a non-ASCII name so UTF-8-specific code paths (e.g.
+/// multi-byte-aware component splitting) get exercised too.
+fn utf8_component_strategy() -> impl Strategy<Value = String> {
+    prop_oneof![
+        6 => "[a-zA-Z0-9_-]{1,8}",
+        1 => Just(".".to_string()),
+        1 => Just("..".to_string()),
+        1 => prop_oneof![Just("café"), Just("résumé"), Just("日本語"), Just("emoji-😀")]
+            .prop_map(|s| s.to_string()),
+    ]
+}
+
+fn push_component(bytes: &mut Vec<u8>, component: &[u8], sep: u8) {
+    if !bytes.is_empty() && *bytes.last().unwrap() != sep {
+        bytes.push(sep);
+    }
+    bytes.extend_from_slice(component);
+}
+
+fn push_str_component(s: &mut String, component: &str, sep: char) {
+    if !s.is_empty() && !s.ends_with(sep) {
+        s.push(sep);
+    }
+    s.push_str(component);
+}
+
+/// Generates raw bytes that parse as a [`UnixPath`](crate::UnixPath): a coin-flip leading `/`
+/// followed by a handful of components.
+fn unix_path_bytes_strategy() -> impl Strategy<Value = Vec<u8>> {
+    (
+        proptest::bool::ANY,
+        prop::collection::vec(component_bytes_strategy(), 0..6),
+    )
+        .prop_map(|(has_root, components)| {
+            let mut bytes = Vec::new();
+            if has_root {
+                bytes.push(b'/');
+            }
+            for component in &components {
+                push_component(&mut bytes, component, b'/');
+            }
+            bytes
+        })
+}
+
+/// Generates raw bytes that parse as a [`WindowsPath`](crate::WindowsPath): an optional drive,
+/// UNC, or verbatim prefix, an optional root, and a handful of components.
+fn windows_path_bytes_strategy() -> impl Strategy<Value = Vec<u8>> {
+    let prefix = prop_oneof![
+        3 => Just(Vec::new()),
+        2 => ('A'..='Z').prop_map(|letter| format!("{letter}:").into_bytes()),
+        1 => ("[a-zA-Z0-9_-]{1,8}", "[a-zA-Z0-9_-]{1,8}")
+            .prop_map(|(server, share)| format!(r"\\{server}\{share}").into_bytes()),
+        1 => ('A'..='Z').prop_map(|letter| format!(r"\\?\{letter}:").into_bytes()),
+    ];
+
+    (
+        prefix,
+        proptest::bool::ANY,
+        prop::collection::vec(component_bytes_strategy(), 0..6),
+    )
+        .prop_map(|(prefix, has_root, components)| {
+            let mut bytes = prefix;
+            if has_root && !bytes.ends_with(b"\\") {
+                bytes.push(b'\\');
+            }
+            for component in &components {
+                push_component(&mut bytes, component, b'\\');
+            }
+            bytes
+        })
+}
+
+/// Generates a `String` that parses as a Unix path, mirroring
+/// [`unix_path_bytes_strategy`] but restricted to valid UTF-8 components.
+fn utf8_unix_path_string_strategy() -> impl Strategy<Value = String> {
+    (
+        proptest::bool::ANY,
+        prop::collection::vec(utf8_component_strategy(), 0..6),
+    )
+        .prop_map(|(has_root, components)| {
+            let mut s = String::new();
+            if has_root {
+                s.push('/');
+            }
+            for component in &components {
+                push_str_component(&mut s, component, '/');
+            }
+            s
+        })
+}
+
+/// Generates a `String` that parses as a Windows path, mirroring
+/// [`windows_path_bytes_strategy`] but restricted to valid UTF-8 components.
+fn utf8_windows_path_string_strategy() -> impl Strategy<Value = String> {
+    let prefix = prop_oneof![
+        3 => Just(String::new()),
+        2 => ('A'..='Z').prop_map(|letter| format!("{letter}:")),
+        1 => ("[a-zA-Z0-9_-]{1,8}", "[a-zA-Z0-9_-]{1,8}")
+            .prop_map(|(server, share)| format!(r"\\{server}\{share}")),
+        1 => ('A'..='Z').prop_map(|letter| format!(r"\\?\{letter}:")),
+    ];
+
+    (
+        prefix,
+        proptest::bool::ANY,
+        prop::collection::vec(utf8_component_strategy(), 0..6),
+    )
+        .prop_map(|(prefix, has_root, components)| {
+            let mut s = prefix;
+            if has_root && !s.ends_with('\\') {
+                s.push('\\');
+            }
+            for component in &components {
+                push_str_component(&mut s, component, '\\');
+            }
+            s
+        })
+}
+
+/// Returns a [`Strategy`] that generates arbitrary [`UnixPathBuf`] values.
+pub fn unix_path_buf_strategy() -> impl Strategy<Value = UnixPathBuf> {
+    unix_path_bytes_strategy().prop_map(UnixPathBuf::from)
+}
+
+/// Returns a [`Strategy`] that generates arbitrary [`WindowsPathBuf`] values.
+pub fn windows_path_buf_strategy() -> impl Strategy<Value = WindowsPathBuf> {
+    windows_path_bytes_strategy().prop_map(WindowsPathBuf::from)
+}
+
+/// Returns a [`Strategy`] that generates arbitrary [`Utf8UnixPathBuf`] values.
+pub fn utf8_unix_path_buf_strategy() -> impl Strategy<Value = Utf8UnixPathBuf> {
+    utf8_unix_path_string_strategy().prop_map(Utf8UnixPathBuf::from)
+}
+
+/// Returns a [`Strategy`] that generates arbitrary [`Utf8WindowsPathBuf`] values.
+pub fn utf8_windows_path_buf_strategy() -> impl Strategy<Value = Utf8WindowsPathBuf> {
+    utf8_windows_path_string_strategy().prop_map(Utf8WindowsPathBuf::from)
+}
+
+/// Returns a [`Strategy`] that generates arbitrary [`TypedPathBuf`] values, split roughly evenly
+/// between Unix- and Windows-style paths.
+pub fn typed_path_buf_strategy() -> impl Strategy<Value = TypedPathBuf> {
+    prop_oneof![
+        unix_path_bytes_strategy().prop_map(TypedPathBuf::from),
+        windows_path_bytes_strategy().prop_map(TypedPathBuf::from),
+    ]
+}
+
+impl Arbitrary for UnixPathBuf {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<UnixPathBuf>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        unix_path_buf_strategy().boxed()
+    }
+}
+
+impl Arbitrary for WindowsPathBuf {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<WindowsPathBuf>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        windows_path_buf_strategy().boxed()
+    }
+}
+
+impl Arbitrary for Utf8UnixPathBuf {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Utf8UnixPathBuf>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        utf8_unix_path_buf_strategy().boxed()
+    }
+}
+
+impl Arbitrary for Utf8WindowsPathBuf {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Utf8WindowsPathBuf>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        utf8_windows_path_buf_strategy().boxed()
+    }
+}
+
+impl Arbitrary for TypedPathBuf {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<TypedPathBuf>;
+
+    fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+        typed_path_buf_strategy().boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn typed_path_buf_strategy_should_round_trip_through_its_own_bytes(
+            path in typed_path_buf_strategy(),
+        ) {
+            let bytes = path.as_bytes().to_vec();
+            prop_assert_eq!(TypedPathBuf::from(bytes), path);
+        }
+
+        #[test]
+        fn unix_path_buf_strategy_should_round_trip_through_its_own_bytes(
+            path in unix_path_buf_strategy(),
+        ) {
+            let bytes = path.as_bytes().to_vec();
+            prop_assert_eq!(UnixPathBuf::from(bytes), path);
+        }
+
+        #[test]
+        fn windows_path_buf_strategy_should_round_trip_through_its_own_bytes(
+            path in windows_path_buf_strategy(),
+        ) {
+            let bytes = path.as_bytes().to_vec();
+            prop_assert_eq!(WindowsPathBuf::from(bytes), path);
+        }
+
+        #[test]
+        fn utf8_unix_path_buf_strategy_should_produce_valid_utf8(
+            path in utf8_unix_path_buf_strategy(),
+        ) {
+            prop_assert!(std::str::from_utf8(path.as_str().as_bytes()).is_ok());
+        }
+
+        #[test]
+        fn utf8_windows_path_buf_strategy_should_produce_valid_utf8(
+            path in utf8_windows_path_buf_strategy(),
+        ) {
+            prop_assert!(std::str::from_utf8(path.as_str().as_bytes()).is_ok());
+        }
+    }
+}
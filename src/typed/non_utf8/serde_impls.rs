@@ -0,0 +1,219 @@
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer, Error as DeError, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::unix::{UnixPathBuf, Utf8UnixPathBuf};
+use crate::windows::{Utf8WindowsPathBuf, WindowsPathBuf};
+
+use super::TypedPathBuf;
+
+/// Implements byte-sequence (de)serialization for a byte-backed path buf type. Deserialization
+/// accepts bytes handed over borrowed, short-lived or owned (only an owned buffer is taken
+/// without a copy), as well as a sequence of byte values, which is how formats without a native
+/// bytes type (e.g. `serde_json`) represent them.
+macro_rules! impl_bytes_serde {
+    ($ty:ty, $visitor:ident, $expecting:literal) => {
+        impl Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_bytes(self.as_bytes())
+            }
+        }
+
+        struct $visitor;
+
+        impl<'de> Visitor<'de> for $visitor {
+            type Value = $ty;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str($expecting)
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                Ok(<$ty>::from(v.to_vec()))
+            }
+
+            fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                Ok(<$ty>::from(v.to_vec()))
+            }
+
+            fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                Ok(<$ty>::from(v))
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element::<u8>()? {
+                    bytes.push(byte);
+                }
+                Ok(<$ty>::from(bytes))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_byte_buf($visitor)
+            }
+        }
+    };
+}
+
+/// Implements transparent string (de)serialization for a UTF-8-guaranteed path buf type,
+/// including borrowed-`&str` deserialization for formats that support it.
+macro_rules! impl_utf8_serde {
+    ($ty:ty, $visitor:ident, $expecting:literal) => {
+        impl Serialize for $ty {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        struct $visitor;
+
+        impl<'de> Visitor<'de> for $visitor {
+            type Value = $ty;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str($expecting)
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                Ok(<$ty>::from(v))
+            }
+
+            fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                Ok(<$ty>::from(v))
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+            where
+                E: DeError,
+            {
+                Ok(<$ty>::from(v))
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                deserializer.deserialize_string($visitor)
+            }
+        }
+    };
+}
+
+// Byte-backed encodings: serialize as a byte sequence and deserialize through the type's own
+// `From<Vec<u8>>` (for `TypedPathBuf`, that's the existing Unix-vs-Windows detection logic).
+impl_bytes_serde!(
+    TypedPathBuf,
+    TypedPathBufVisitor,
+    "a byte sequence representing a Unix or Windows path"
+);
+impl_bytes_serde!(
+    UnixPathBuf,
+    UnixPathBufVisitor,
+    "a byte sequence representing a Unix path"
+);
+impl_bytes_serde!(
+    WindowsPathBuf,
+    WindowsPathBufVisitor,
+    "a byte sequence representing a Windows path"
+);
+
+// UTF-8-guaranteed encodings: serialize transparently as a string, indistinguishable from a
+// plain `String` in a human-readable format like JSON or TOML.
+impl_utf8_serde!(
+    Utf8UnixPathBuf,
+    Utf8UnixPathBufVisitor,
+    "a string representing a Unix path"
+);
+impl_utf8_serde!(
+    Utf8WindowsPathBuf,
+    Utf8WindowsPathBufVisitor,
+    "a string representing a Windows path"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_path_buf_should_serialize_as_bytes() {
+        let path = TypedPathBuf::from(b"/tmp/foo.txt".as_slice());
+        let value = serde_json::to_value(&path).unwrap();
+        assert_eq!(value, serde_json::json!(b"/tmp/foo.txt".to_vec()));
+    }
+
+    #[test]
+    fn typed_path_buf_should_round_trip_through_serde() {
+        let path = TypedPathBuf::from(b"/tmp/foo.txt".as_slice());
+        let serialized = serde_json::to_vec(&path).unwrap();
+        let deserialized: TypedPathBuf = serde_json::from_slice(&serialized).unwrap();
+        assert_eq!(path, deserialized);
+    }
+
+    #[test]
+    fn unix_path_buf_should_round_trip_through_serde_as_bytes() {
+        let path = UnixPathBuf::from(b"/tmp/foo.txt".to_vec());
+        let serialized = serde_json::to_vec(&path).unwrap();
+        let deserialized: UnixPathBuf = serde_json::from_slice(&serialized).unwrap();
+        assert_eq!(path, deserialized);
+    }
+
+    #[test]
+    fn windows_path_buf_should_round_trip_through_serde_as_bytes() {
+        let path = WindowsPathBuf::from(br"C:\Users\a.txt".to_vec());
+        let serialized = serde_json::to_vec(&path).unwrap();
+        let deserialized: WindowsPathBuf = serde_json::from_slice(&serialized).unwrap();
+        assert_eq!(path, deserialized);
+    }
+
+    #[test]
+    fn utf8_unix_path_buf_should_serialize_transparently_as_a_string() {
+        let path = Utf8UnixPathBuf::from("/tmp/foo.txt");
+        let value = serde_json::to_value(&path).unwrap();
+        assert_eq!(value, serde_json::json!("/tmp/foo.txt"));
+
+        let deserialized: Utf8UnixPathBuf = serde_json::from_value(value).unwrap();
+        assert_eq!(path, deserialized);
+    }
+
+    #[test]
+    fn utf8_windows_path_buf_should_serialize_transparently_as_a_string() {
+        let path = Utf8WindowsPathBuf::from(r"C:\Users\a.txt");
+        let value = serde_json::to_value(&path).unwrap();
+        assert_eq!(value, serde_json::json!(r"C:\Users\a.txt"));
+
+        let deserialized: Utf8WindowsPathBuf = serde_json::from_value(value).unwrap();
+        assert_eq!(path, deserialized);
+    }
+}
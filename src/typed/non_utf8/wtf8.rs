@@ -0,0 +1,188 @@
+//! Minimal WTF-8 <-> WTF-16 codec used to bridge byte-backed paths to [`OsStr`](std::ffi::OsStr)
+//! losslessly on every platform, following the approach gix-path uses: on Unix the bytes *are*
+//! the `OsStr` representation, while on Windows they are decoded as WTF-8 (UTF-8 that additionally
+//! permits lone surrogates) into the 16-bit units `OsString` is built from, so unpaired surrogates
+//! created by lossy Windows APIs survive the round trip instead of getting rejected or mangled.
+
+use std::fmt;
+
+/// Indicates that a byte sequence was not well-formed WTF-8 and could not be losslessly converted.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Wtf8Error;
+
+impl fmt::Display for Wtf8Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "byte sequence is not valid WTF-8")
+    }
+}
+
+impl std::error::Error for Wtf8Error {}
+
+/// Decodes `bytes` as WTF-8 into WTF-16 code units, preserving lone surrogates.
+pub fn decode(bytes: &[u8]) -> Result<Vec<u16>, Wtf8Error> {
+    let mut units = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let b0 = bytes[i];
+
+        if b0 < 0x80 {
+            units.push(b0 as u16);
+            i += 1;
+            continue;
+        }
+
+        let (len, min, mut cp) = if b0 & 0xE0 == 0xC0 {
+            (2, 0x80u32, (b0 & 0x1F) as u32)
+        } else if b0 & 0xF0 == 0xE0 {
+            (3, 0x800u32, (b0 & 0x0F) as u32)
+        } else if b0 & 0xF8 == 0xF0 {
+            (4, 0x10000u32, (b0 & 0x07) as u32)
+        } else {
+            return Err(Wtf8Error);
+        };
+
+        if i + len > bytes.len() {
+            return Err(Wtf8Error);
+        }
+
+        for &continuation in &bytes[i + 1..i + len] {
+            if continuation & 0xC0 != 0x80 {
+                return Err(Wtf8Error);
+            }
+            cp = (cp << 6) | (continuation & 0x3F) as u32;
+        }
+
+        if cp < min || cp > 0x10FFFF {
+            return Err(Wtf8Error);
+        }
+
+        if cp >= 0x10000 {
+            let cp = cp - 0x10000;
+            units.push(0xD800 + (cp >> 10) as u16);
+            units.push(0xDC00 + (cp & 0x3FF) as u16);
+        } else if (0xD800..=0xDBFF).contains(&cp) {
+            // A high surrogate encoded as its own 3-byte sequence is only well-formed WTF-8 if
+            // it is *not* immediately followed by a low surrogate's 3-byte sequence -- such a
+            // pair must instead be encoded as the single 4-byte sequence for the astral
+            // codepoint it represents. Accepting it here would let `encode` silently rewrite
+            // the bytes into that canonical 4-byte form on the next round trip.
+            if let Some(&next) = bytes.get(i + len) {
+                if next & 0xF0 == 0xE0 && i + len + 3 <= bytes.len() {
+                    let low = &bytes[i + len..i + len + 3];
+                    if low[1] & 0xC0 == 0x80 && low[2] & 0xC0 == 0x80 {
+                        let low_cp = ((low[0] & 0x0F) as u32) << 12
+                            | ((low[1] & 0x3F) as u32) << 6
+                            | (low[2] & 0x3F) as u32;
+                        if (0xDC00..=0xDFFF).contains(&low_cp) {
+                            return Err(Wtf8Error);
+                        }
+                    }
+                }
+            }
+
+            units.push(cp as u16);
+        } else {
+            // NOTE: unlike strict UTF-8, we deliberately accept surrogate code points
+            // (0xD800..=0xDFFF) here -- that's what makes this WTF-8 rather than UTF-8.
+            units.push(cp as u16);
+        }
+
+        i += len;
+    }
+
+    Ok(units)
+}
+
+/// Encodes WTF-16 code `units`, including any lone surrogates, as WTF-8 bytes.
+pub fn encode(units: &[u16]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(units.len());
+    let mut i = 0;
+
+    while i < units.len() {
+        let unit = units[i];
+
+        let cp = if (0xD800..=0xDBFF).contains(&unit)
+            && i + 1 < units.len()
+            && (0xDC00..=0xDFFF).contains(&units[i + 1])
+        {
+            let high = (unit - 0xD800) as u32;
+            let low = (units[i + 1] - 0xDC00) as u32;
+            i += 2;
+            0x10000 + (high << 10) + low
+        } else {
+            i += 1;
+            unit as u32
+        };
+
+        if cp < 0x80 {
+            bytes.push(cp as u8);
+        } else if cp < 0x800 {
+            bytes.push(0xC0 | (cp >> 6) as u8);
+            bytes.push(0x80 | (cp & 0x3F) as u8);
+        } else if cp < 0x10000 {
+            bytes.push(0xE0 | (cp >> 12) as u8);
+            bytes.push(0x80 | ((cp >> 6) & 0x3F) as u8);
+            bytes.push(0x80 | (cp & 0x3F) as u8);
+        } else {
+            bytes.push(0xF0 | (cp >> 18) as u8);
+            bytes.push(0x80 | ((cp >> 12) & 0x3F) as u8);
+            bytes.push(0x80 | ((cp >> 6) & 0x3F) as u8);
+            bytes.push(0x80 | (cp & 0x3F) as u8);
+        }
+    }
+
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_round_trip_ascii() {
+        let units = decode(b"hello.txt").unwrap();
+        assert_eq!(encode(&units), b"hello.txt");
+    }
+
+    #[test]
+    fn should_round_trip_multibyte_utf8() {
+        let bytes = "résumé.txt".as_bytes();
+        let units = decode(bytes).unwrap();
+        assert_eq!(encode(&units), bytes);
+    }
+
+    #[test]
+    fn should_round_trip_astral_characters_as_surrogate_pairs() {
+        let bytes = "\u{1F600}.txt".as_bytes();
+        let units = decode(bytes).unwrap();
+        assert_eq!(units[0], 0xD83D);
+        assert_eq!(units[1], 0xDE00);
+        assert_eq!(encode(&units), bytes);
+    }
+
+    #[test]
+    fn should_round_trip_a_lone_surrogate() {
+        // 0xD800 encoded as an (invalid-UTF-8, valid-WTF-8) 3-byte sequence.
+        let bytes = [0xED, 0xA0, 0x80, b'x'];
+        let units = decode(&bytes).unwrap();
+        assert_eq!(units, vec![0xD800, b'x' as u16]);
+        assert_eq!(encode(&units), bytes);
+    }
+
+    #[test]
+    fn should_reject_invalid_wtf8() {
+        assert_eq!(decode(&[0xC0]), Err(Wtf8Error));
+        assert_eq!(decode(&[0xFF]), Err(Wtf8Error));
+    }
+
+    #[test]
+    fn should_reject_a_surrogate_pair_split_across_two_three_byte_sequences() {
+        // The 3-byte WTF-8 encodings of the lone high surrogate 0xD800 and lone low surrogate
+        // 0xDC00, back to back. Accepting this would let `encode` silently rewrite it into the
+        // canonical 4-byte encoding of the astral codepoint it spells out, corrupting the bytes
+        // on round trip instead of leaving the (individually valid) lone surrogates alone.
+        let bytes = [0xED, 0xA0, 0x80, 0xED, 0xB0, 0x80];
+        assert_eq!(decode(&bytes), Err(Wtf8Error));
+    }
+}